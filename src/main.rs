@@ -1,4 +1,63 @@
-use std::collections::{HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+
+/// A configurable set of moves the search may take from a cell.
+///
+/// Each move is a `(dr, dc, glyph)` offset: the row/column delta to a neighbor
+/// and the character used to render that step in a path string. Use
+/// [`Moves::four_connected`] (the default) for the classic `>`/`v`/`<`/`^`
+/// moves, [`Moves::eight_connected`] to add diagonals, or
+/// [`Moves::from_offsets`] for arbitrary adjacency such as knight moves.
+#[derive(Clone)]
+pub struct Moves {
+    offsets: Vec<(isize, isize, char)>,
+}
+
+impl Moves {
+    /// The default 4-connected moves: right, down, left, up.
+    pub fn four_connected() -> Self {
+        Moves {
+            offsets: vec![(0, 1, '>'), (1, 0, 'v'), (0, -1, '<'), (-1, 0, '^')],
+        }
+    }
+
+    /// The 8-connected (king-move) set: the four orthogonal moves plus the four
+    /// diagonals, glyphed with `↗↘↙↖`.
+    pub fn eight_connected() -> Self {
+        let mut offsets = Self::four_connected().offsets;
+        offsets.extend([
+            (-1, 1, '↗'),
+            (1, 1, '↘'),
+            (1, -1, '↙'),
+            (-1, -1, '↖'),
+        ]);
+        Moves { offsets }
+    }
+
+    /// Build a move set from an arbitrary list of `(dr, dc, glyph)` offsets,
+    /// e.g. knight moves or hex-style adjacency.
+    pub fn from_offsets(offsets: Vec<(isize, isize, char)>) -> Self {
+        Moves { offsets }
+    }
+
+    /// The glyph for the single step from `from` to its neighbor `to`, or `'?'`
+    /// if no configured move matches that delta.
+    fn glyph(&self, from: (usize, usize), to: (usize, usize)) -> char {
+        let dr = to.0 as isize - from.0 as isize;
+        let dc = to.1 as isize - from.1 as isize;
+        self.offsets
+            .iter()
+            .find(|&&(odr, odc, _)| odr == dr && odc == dc)
+            .map(|&(_, _, glyph)| glyph)
+            .unwrap_or('?')
+    }
+}
+
+impl Default for Moves {
+    fn default() -> Self {
+        Moves::four_connected()
+    }
+}
 
 /// Find all shortest paths between start and end numbers in a grid
 /// Returns paths as strings of directional characters: '<', '>', '^', 'v'
@@ -19,27 +78,241 @@ pub fn find_all_shortest_paths(
     start_number: i32,
     end_number: i32,
 ) -> Vec<String> {
-    // Find the positions of start and end numbers
-    let mut start_pos = None;
-    let mut end_pos = None;
+    // No cells are blocked: delegate to the blocked-aware variant.
+    find_all_shortest_paths_blocked(grid, start_number, end_number, &HashSet::new())
+}
+
+/// Locate the cells holding numbers `a` and `b`, returning `Some((a_pos, b_pos))`
+/// or `None` if either number is absent. Assumes each number appears at most once.
+fn find_positions(
+    grid: &Vec<Vec<i32>>,
+    a: i32,
+    b: i32,
+) -> Option<((usize, usize), (usize, usize))> {
+    let mut a_pos = None;
+    let mut b_pos = None;
 
     for (row, row_values) in grid.iter().enumerate() {
         for (col, &value) in row_values.iter().enumerate() {
-            if value == start_number {
-                start_pos = Some((row, col));
-            } else if value == end_number {
-                end_pos = Some((row, col));
+            if value == a {
+                a_pos = Some((row, col));
+            } else if value == b {
+                b_pos = Some((row, col));
             }
         }
     }
 
+    Some((a_pos?, b_pos?))
+}
+
+/// Find all shortest paths between start and end numbers in a grid, treating the
+/// cells in `blocked` as impassable walls/obstacles.
+///
+/// # Arguments
+/// * `grid` - A 2D vector representing the grid of numbers
+/// * `start_number` - The number to start from
+/// * `end_number` - The number to end at
+/// * `blocked` - Set of `(row, col)` coordinates that may not be entered
+///
+/// # Returns
+/// * A vector of paths, where each path is a string of direction characters
+///
+/// # Note
+/// * Assumes each number appears exactly once in the grid
+/// * Returns an empty vector if the start or end cell is itself blocked
+pub fn find_all_shortest_paths_blocked(
+    grid: &Vec<Vec<i32>>,
+    start_number: i32,
+    end_number: i32,
+    blocked: &HashSet<(usize, usize)>,
+) -> Vec<String> {
     // If either start or end number is not found, return empty vector
-    if start_pos.is_none() || end_pos.is_none() {
+    let (start, end) = match find_positions(grid, start_number, end_number) {
+        Some(positions) => positions,
+        None => return vec![],
+    };
+
+    // If the start or end cell is walled off, no path can exist
+    if blocked.contains(&start) || blocked.contains(&end) {
         return vec![];
     }
 
     // Find all shortest paths between the start and end positions
-    bfs_shortest_paths(grid, start_pos.unwrap(), end_pos.unwrap())
+    bfs_shortest_paths(grid, start, end, blocked, &Moves::four_connected(), |_, _| true)
+}
+
+/// Find all shortest paths between start and end numbers where a move from one
+/// cell to an adjacent cell is only legal when `can_move(current_value,
+/// neighbor_value)` returns true.
+///
+/// This generalises the crate from unique-number connectivity to arbitrary
+/// monotone/terrain rules. For example, to only step up by at most one unit of
+/// elevation, pass `|cur, next| next <= cur + 1`.
+///
+/// # Arguments
+/// * `grid` - A 2D vector representing the grid of numbers
+/// * `start_number` - The number to start from
+/// * `end_number` - The number to end at
+/// * `can_move` - Predicate deciding whether a move between two cell values is legal
+///
+/// # Returns
+/// * A vector of paths, where each path is a string of direction characters
+pub fn find_all_shortest_paths_with(
+    grid: &Vec<Vec<i32>>,
+    start_number: i32,
+    end_number: i32,
+    can_move: impl Fn(i32, i32) -> bool,
+) -> Vec<String> {
+    // If either start or end number is not found, return empty vector
+    let (start, end) = match find_positions(grid, start_number, end_number) {
+        Some(positions) => positions,
+        None => return vec![],
+    };
+
+    bfs_shortest_paths(
+        grid,
+        start,
+        end,
+        &HashSet::new(),
+        &Moves::four_connected(),
+        can_move,
+    )
+}
+
+/// Shortest-path predecessor DAG produced by a single BFS.
+///
+/// `predecessors[cell]` holds every cell that first reached `cell` at its
+/// shortest distance, so the set of shortest paths is encoded compactly and can
+/// be reconstructed (or counted) on demand by walking backward from the end,
+/// without ever carrying a path string through the queue.
+struct PathDag {
+    start: (usize, usize),
+    predecessors: std::collections::HashMap<(usize, usize), Vec<(usize, usize)>>,
+    moves: Moves,
+    reached: bool,
+}
+
+/// Run a single clean BFS and record, for each cell, the set of predecessor
+/// cells on a shortest path to it. Neighbors are taken from the configurable
+/// `moves` set; out-of-bounds, `blocked`, and moves rejected by `can_move` are
+/// all skipped during neighbor expansion.
+fn build_path_dag(
+    grid: &Vec<Vec<i32>>,
+    start: (usize, usize),
+    end: (usize, usize),
+    blocked: &HashSet<(usize, usize)>,
+    moves: &Moves,
+    can_move: impl Fn(i32, i32) -> bool,
+) -> PathDag {
+    let rows = grid.len();
+    let cols = if rows > 0 { grid[0].len() } else { 0 };
+
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    // Shortest distance to each discovered cell.
+    let mut distance = std::collections::HashMap::new();
+    distance.insert(start, 0usize);
+
+    let mut predecessors: std::collections::HashMap<(usize, usize), Vec<(usize, usize)>> =
+        std::collections::HashMap::new();
+
+    while let Some(current) = queue.pop_front() {
+        let level = distance[&current];
+
+        // Explore every configured move
+        for &(dr, dc, _) in moves.offsets.iter() {
+            let new_row = current.0 as isize + dr;
+            let new_col = current.1 as isize + dc;
+
+            // Check if the new position is valid
+            if new_row >= 0 && new_row < rows as isize &&
+                new_col >= 0 && new_col < cols as isize {
+                let new_pos = (new_row as usize, new_col as usize);
+
+                // Skip impassable cells, just like out-of-bounds ones
+                if blocked.contains(&new_pos) {
+                    continue;
+                }
+
+                // Respect the move-validity rule on the two cell values
+                if !can_move(grid[current.0][current.1], grid[new_pos.0][new_pos.1]) {
+                    continue;
+                }
+
+                let new_level = level + 1;
+
+                match distance.get(&new_pos) {
+                    // First time we reach this cell: record distance and predecessor
+                    None => {
+                        distance.insert(new_pos, new_level);
+                        predecessors.insert(new_pos, vec![current]);
+                        queue.push_back(new_pos);
+                    }
+                    // Reached again at the same shortest distance: add a predecessor
+                    Some(&existing) if existing == new_level => {
+                        predecessors.entry(new_pos).or_default().push(current);
+                    }
+                    // A strictly longer route: ignore
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let reached = start == end || distance.contains_key(&end);
+    PathDag {
+        start,
+        predecessors,
+        moves: moves.clone(),
+        reached,
+    }
+}
+
+/// Walk the predecessor DAG backward from `cell` to the start, materialising the
+/// direction string of every shortest path. This is the only place path strings
+/// are built, and only when a caller asks to enumerate them.
+fn reconstruct_paths(dag: &PathDag, cell: (usize, usize)) -> Vec<String> {
+    if cell == dag.start {
+        return vec![String::new()];
+    }
+
+    let mut paths = vec![];
+    if let Some(preds) = dag.predecessors.get(&cell) {
+        for &pred in preds {
+            let glyph = dag.moves.glyph(pred, cell);
+            for mut prefix in reconstruct_paths(dag, pred) {
+                prefix.push(glyph);
+                paths.push(prefix);
+            }
+        }
+    }
+    paths
+}
+
+/// Number of distinct shortest paths reaching `cell`, by a DP over the DAG:
+/// `count[start] = 1` and `count[cell] = sum of count[pred]`. Memoised so the
+/// count never materialises the individual paths.
+fn count_paths(
+    dag: &PathDag,
+    cell: (usize, usize),
+    memo: &mut std::collections::HashMap<(usize, usize), u64>,
+) -> u64 {
+    if cell == dag.start {
+        return 1;
+    }
+    if let Some(&cached) = memo.get(&cell) {
+        return cached;
+    }
+
+    let mut total = 0u64;
+    if let Some(preds) = dag.predecessors.get(&cell) {
+        for &pred in preds {
+            total += count_paths(dag, pred, memo);
+        }
+    }
+    memo.insert(cell, total);
+    total
 }
 
 /// Helper function to find all shortest paths using BFS
@@ -48,44 +321,153 @@ fn bfs_shortest_paths(
     grid: &Vec<Vec<i32>>,
     start: (usize, usize),
     end: (usize, usize),
+    blocked: &HashSet<(usize, usize)>,
+    moves: &Moves,
+    can_move: impl Fn(i32, i32) -> bool,
 ) -> Vec<String> {
-    let rows = grid.len();
-    let cols = if rows > 0 { grid[0].len() } else { 0 };
+    let dag = build_path_dag(grid, start, end, blocked, moves, can_move);
+    if !dag.reached {
+        return vec![];
+    }
+    reconstruct_paths(&dag, end)
+}
+
+/// Find all shortest paths between start and end numbers using a configurable
+/// movement set, e.g. [`Moves::eight_connected`] for king-moves or a custom
+/// [`Moves::from_offsets`] adjacency.
+///
+/// # Arguments
+/// * `grid` - A 2D vector representing the grid of numbers
+/// * `start_number` - The number to start from
+/// * `end_number` - The number to end at
+/// * `moves` - The movement set to expand neighbors with
+///
+/// # Returns
+/// * A vector of paths, where each path is a string of the moves' glyphs
+pub fn find_all_shortest_paths_moves(
+    grid: &Vec<Vec<i32>>,
+    start_number: i32,
+    end_number: i32,
+    moves: &Moves,
+) -> Vec<String> {
+    let (start, end) = match find_positions(grid, start_number, end_number) {
+        Some(positions) => positions,
+        None => return vec![],
+    };
+
+    bfs_shortest_paths(grid, start, end, &HashSet::new(), moves, |_, _| true)
+}
+
+/// Count the number of shortest paths between start and end numbers in a grid.
+///
+/// This reuses the predecessor-DAG BFS but runs a simple DP over it, so callers
+/// that only need the count never pay to enumerate the (potentially
+/// exponentially many) path strings.
+///
+/// # Arguments
+/// * `grid` - A 2D vector representing the grid of numbers
+/// * `start_number` - The number to start from
+/// * `end_number` - The number to end at
+///
+/// # Returns
+/// * The number of distinct shortest paths, or 0 if a number is missing or the
+///   end is unreachable
+pub fn count_shortest_paths(grid: &Vec<Vec<i32>>, start_number: i32, end_number: i32) -> u64 {
+    // Find the positions of start and end numbers
+    let (start, end) = match find_positions(grid, start_number, end_number) {
+        Some(positions) => positions,
+        None => return 0,
+    };
+
+    let dag = build_path_dag(grid, start, end, &HashSet::new(), &Moves::four_connected(), |_, _| true);
+    if !dag.reached {
+        return 0;
+    }
+
+    let mut memo = std::collections::HashMap::new();
+    count_paths(&dag, end, &mut memo)
+}
+
+/// Find all minimum-cost paths between start and end numbers on a weighted grid.
+///
+/// Where [`find_all_shortest_paths`] charges 1 per step, here entering a cell
+/// costs that cell's weight in `weights`. Because step costs differ, the search
+/// uses Dijkstra with a cost-ordered [`BinaryHeap`] instead of the FIFO BFS
+/// queue, while preserving the "return all optimal paths" behaviour.
+///
+/// # Arguments
+/// * `grid` - A 2D vector of numbers used to locate the start and end cells
+/// * `weights` - A grid of non-negative entry costs, same shape as `grid`
+/// * `start_number` - The number to start from
+/// * `end_number` - The number to end at
+///
+/// # Returns
+/// * `Some((total_cost, paths))` where `paths` are the direction strings of the
+///   cheapest routes, or `None` if a number is missing or the end is unreachable
+pub fn find_all_cheapest_paths(
+    grid: &Vec<Vec<i32>>,
+    weights: &Vec<Vec<i32>>,
+    start_number: i32,
+    end_number: i32,
+) -> Option<(i32, Vec<String>)> {
+    // Find the positions of start and end numbers
+    let (start, end) = find_positions(grid, start_number, end_number)?;
+
+    // `weights` must have the same shape as `grid`; a smaller grid would index
+    // out of bounds during expansion, so reject a mismatch rather than panic.
+    if weights.len() != grid.len()
+        || grid.iter().zip(weights).any(|(g, w)| g.len() != w.len())
+    {
+        return None;
+    }
 
-    // Define possible movements: right, down, left, up
-    // The index corresponds to the direction: 0 = right (>), 1 = down (v), 2 = left (<), 3 = up (^)
+    let (cost, paths) = dijkstra_cheapest_paths(weights, start, end);
+    if paths.is_empty() {
+        None
+    } else {
+        Some((cost, paths))
+    }
+}
+
+/// Helper function to find all minimum-cost paths using Dijkstra's algorithm.
+/// Returns the total cost and the paths as strings of directional characters.
+fn dijkstra_cheapest_paths(
+    weights: &Vec<Vec<i32>>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> (i32, Vec<String>) {
+    let rows = weights.len();
+    let cols = if rows > 0 { weights[0].len() } else { 0 };
+
+    // Same four moves and glyphs as the unweighted search.
     let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
     let direction_chars = ['>', 'v', '<', '^'];
 
-    // Queue for BFS - stores (position, path_string, level)
-    // We add level to track distance from start
-    let mut queue = VecDeque::new();
-    queue.push_back((start, String::new(), 0));
+    // Min-heap keyed by accumulated cost - stores (cost, position, path_string).
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, start, String::new())));
 
-    // Track distance to each cell (used instead of a simple visited set)
-    let mut distance = std::collections::HashMap::new();
-    distance.insert(start, 0);
+    // Best known cost to each cell (the Dijkstra relaxation bound).
+    let mut best_cost = std::collections::HashMap::new();
+    best_cost.insert(start, 0);
 
-    // Store all shortest paths
-    let mut shortest_paths = vec![];
-    let mut shortest_distance = usize::MAX;
+    // Store all cheapest paths
+    let mut cheapest_paths = vec![];
+    let mut cheapest_cost = i32::MAX;
 
-    while let Some((current, path, level)) = queue.pop_front() {
-        // If we've already found shorter paths, and this path is longer, skip it
-        if !shortest_paths.is_empty() && level > shortest_distance {
+    while let Some(Reverse((cost, current, path))) = heap.pop() {
+        // Heap pops in cost order, so anything dearer than a found optimum is done
+        if !cheapest_paths.is_empty() && cost > cheapest_cost {
             continue;
         }
 
-        // If we found a path to the end
+        // If we reached the end, record this optimal path
         if current == end {
-            // If this is shorter than our current shortest, reset the list
-            if level < shortest_distance {
-                shortest_paths = vec![path];
-                shortest_distance = level;
-            }
-            // If it's the same length as our shortest, add it to the list
-            else if level == shortest_distance {
-                shortest_paths.push(path);
+            if cost < cheapest_cost {
+                cheapest_paths = vec![path];
+                cheapest_cost = cost;
+            } else if cost == cheapest_cost {
+                cheapest_paths.push(path);
             }
             continue;
         }
@@ -95,29 +477,132 @@ fn bfs_shortest_paths(
             let new_row = current.0 as isize + dr;
             let new_col = current.1 as isize + dc;
 
-            // Check if the new position is valid
             if new_row >= 0 && new_row < rows as isize &&
                 new_col >= 0 && new_col < cols as isize {
                 let new_pos = (new_row as usize, new_col as usize);
-                let new_level = level + 1;
+                let new_cost = cost + weights[new_pos.0][new_pos.1];
 
-                // Visit this cell if:
-                // 1. We haven't seen it before, OR
-                // 2. We've found an equally short path to it
-                if !distance.contains_key(&new_pos) || distance[&new_pos] == new_level {
-                    // Update distance
-                    distance.insert(new_pos, new_level);
+                // Relax when we reach the cell more cheaply, and allow equal-cost
+                // arrivals through so every optimal path is enumerated.
+                if !best_cost.contains_key(&new_pos) || new_cost <= best_cost[&new_pos] {
+                    best_cost.insert(new_pos, new_cost);
 
-                    // Create new path by extending the current path with the direction character
                     let mut new_path = path.clone();
                     new_path.push(direction_chars[dir_idx]);
-                    queue.push_back((new_pos, new_path, new_level));
+                    heap.push(Reverse((new_cost, new_pos, new_path)));
                 }
             }
         }
     }
 
-    shortest_paths
+    (cheapest_cost, cheapest_paths)
+}
+
+/// Find the shortest walk that visits every number in `targets`.
+///
+/// The first entry of `targets` is treated as the fixed starting number; the
+/// remaining numbers may be visited in any order. This is the classic
+/// "visit all goals" problem solved in two stages: first the pairwise shortest
+/// distances between the targets are computed with BFS, then the orderings of
+/// the non-fixed targets are brute-forced to minimise the total walk length.
+///
+/// # Arguments
+/// * `grid` - A 2D vector representing the grid of numbers
+/// * `targets` - The numbers to visit; `targets[0]` is the fixed start
+///
+/// # Returns
+/// * `Some((total_distance, path))` for the best ordering, where `path` is the
+///   concatenated direction string, or `None` if any target is missing from the
+///   grid or no ordering connects all of them
+pub fn find_shortest_route(grid: &Vec<Vec<i32>>, targets: &[i32]) -> Option<(usize, String)> {
+    if targets.is_empty() {
+        return Some((0, String::new()));
+    }
+
+    // Locate the cell of each target number; bail out if any is missing.
+    let mut positions = Vec::with_capacity(targets.len());
+    for &number in targets {
+        let mut found = None;
+        for (row, row_values) in grid.iter().enumerate() {
+            for (col, &value) in row_values.iter().enumerate() {
+                if value == number {
+                    found = Some((row, col));
+                }
+            }
+        }
+        positions.push(found?);
+    }
+
+    // Build N×N matrices of the pairwise shortest distance and a representative
+    // path between every pair of targets. A missing entry marks an unreachable
+    // pair.
+    let n = positions.len();
+    let mut dist = vec![vec![None; n]; n];
+    let mut path = vec![vec![String::new(); n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            if i == j {
+                dist[i][j] = Some(0);
+                continue;
+            }
+            let paths =
+                bfs_shortest_paths(grid, positions[i], positions[j], &HashSet::new(), &Moves::four_connected(), |_, _| true);
+            if let Some(first) = paths.into_iter().next() {
+                dist[i][j] = Some(first.len());
+                path[i][j] = first;
+            }
+        }
+    }
+
+    // Brute-force the orderings of the non-fixed targets (indices 1..n).
+    let mut best: Option<(usize, String)> = None;
+    for order in permutations(&(1..n).collect::<Vec<usize>>()) {
+        let mut sequence = Vec::with_capacity(n);
+        sequence.push(0);
+        sequence.extend(order);
+
+        let mut total = 0;
+        let mut route = String::new();
+        let mut reachable = true;
+        for window in sequence.windows(2) {
+            let (from, to) = (window[0], window[1]);
+            match dist[from][to] {
+                Some(d) => {
+                    total += d;
+                    route.push_str(&path[from][to]);
+                }
+                None => {
+                    reachable = false;
+                    break;
+                }
+            }
+        }
+
+        if reachable && best.as_ref().is_none_or(|(b, _)| total < *b) {
+            best = Some((total, route));
+        }
+    }
+
+    best
+}
+
+/// Return every permutation of `items`, by removing each item in turn and
+/// recursing on the rest.
+fn permutations<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return vec![vec![]];
+    }
+
+    let mut result = vec![];
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let head = rest.remove(i);
+        for mut perm in permutations(&rest) {
+            perm.insert(0, head.clone());
+            result.push(perm);
+        }
+    }
+    result
 }
 
 /// Example usage
@@ -152,11 +637,12 @@ mod tests {
         // Test path from 1 to 9
         let paths = find_all_shortest_paths(&grid, 1, 9);
         println!("Paths: {:?}", paths);
-        assert_eq!(paths.len(), 2); // There should be 2 paths of equal length
+        assert_eq!(paths.len(), 6); // All 6 monotone corner-to-corner paths
 
-        // Both paths should have length 4 (4 movement directions)
-        assert_eq!(paths[0].len(), 4);
-        assert_eq!(paths[1].len(), 4);
+        // Every path should have length 4 (4 movement directions)
+        for path in &paths {
+            assert_eq!(path.len(), 4);
+        }
 
         // Test that the paths are correctly represented
         // From 1 to 9, the paths should be ">>vv" (right, right, down, down)
@@ -208,4 +694,159 @@ mod tests {
         assert_eq!(paths.len(), 2);
         assert!(paths.contains(&String::from(">v")) || paths.contains(&String::from("v>")));
     }
+
+    #[test]
+    fn test_blocked_cells() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9]
+        ];
+
+        // Wall off the center cell (value 5). Four of the six shortest paths from
+        // 1 to 9 pass through it, so only the two edge routes remain.
+        let blocked: HashSet<(usize, usize)> = [(1, 1)].into_iter().collect();
+        let paths = find_all_shortest_paths_blocked(&grid, 1, 9, &blocked);
+        assert_eq!(paths.len(), 2);
+        for path in &paths {
+            assert_eq!(path.len(), 4);
+        }
+
+        // Wall off cells 2 and 4 (both neighbors of 1): 1 is boxed in and 9 is
+        // unreachable.
+        let blocked: HashSet<(usize, usize)> = [(0, 1), (1, 0)].into_iter().collect();
+        assert!(find_all_shortest_paths_blocked(&grid, 1, 9, &blocked).is_empty());
+
+        // Blocking the end cell itself yields no paths.
+        let blocked: HashSet<(usize, usize)> = [(2, 2)].into_iter().collect();
+        assert!(find_all_shortest_paths_blocked(&grid, 1, 9, &blocked).is_empty());
+    }
+
+    #[test]
+    fn test_find_shortest_route() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9]
+        ];
+
+        // Start at 1, then visit 3 and 7 in either order. 1->3 costs 2 and
+        // 3->7 costs 4, versus 1->7 (2) + 7->3 (4); both orderings total 6.
+        let (total, route) = find_shortest_route(&grid, &[1, 3, 7]).unwrap();
+        assert_eq!(total, 6);
+        assert_eq!(route.len(), 6);
+
+        // A single target needs no movement.
+        assert_eq!(find_shortest_route(&grid, &[5]), Some((0, String::new())));
+
+        // A number that is absent from the grid yields no route.
+        assert_eq!(find_shortest_route(&grid, &[1, 42]), None);
+    }
+
+    #[test]
+    fn test_find_shortest_paths_with_predicate() {
+        // Grid where the center row is an elevation wall the rule forbids climbing.
+        let grid = vec![
+            vec![1, 1, 1],
+            vec![9, 9, 9],
+            vec![2, 2, 2]
+        ];
+
+        // Only step to a cell at most one greater than the current one. Crossing
+        // the 9-row from a 1-cell is illegal, so 1 cannot reach the bottom row.
+        let paths = find_all_shortest_paths_with(&grid, 1, 2, |cur, next| next <= cur + 1);
+        assert!(paths.is_empty());
+
+        // An always-true predicate reproduces the unconstrained search.
+        let open = vec![
+            vec![1, 2],
+            vec![3, 4]
+        ];
+        let a = find_all_shortest_paths_with(&open, 1, 4, |_, _| true);
+        let b = find_all_shortest_paths(&open, 1, 4);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_find_cheapest_paths() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9]
+        ];
+
+        // Make the center (value 5) expensive so the four routes that pass
+        // through it are no longer cheapest.
+        let weights = vec![
+            vec![1, 1, 1],
+            vec![1, 9, 1],
+            vec![1, 1, 1]
+        ];
+
+        let (cost, paths) = find_all_cheapest_paths(&grid, &weights, 1, 9).unwrap();
+        // Cheapest routes skirt the center: 2 + 3 + 6 + 9 style edges, cost 4.
+        assert_eq!(cost, 4);
+        assert!(paths.contains(&String::from(">>vv")));
+        assert!(paths.contains(&String::from("vv>>")));
+        for path in &paths {
+            assert_eq!(path.len(), 4);
+        }
+
+        // Uniform weights reproduce the unweighted optimum count.
+        let uniform = vec![
+            vec![1, 1, 1],
+            vec![1, 1, 1],
+            vec![1, 1, 1]
+        ];
+        let (cost, paths) = find_all_cheapest_paths(&grid, &uniform, 1, 9).unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(paths.len(), find_all_shortest_paths(&grid, 1, 9).len());
+
+        // Missing number yields None.
+        assert_eq!(find_all_cheapest_paths(&grid, &uniform, 1, 42), None);
+    }
+
+    #[test]
+    fn test_count_shortest_paths() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9]
+        ];
+
+        // The count must agree with the number of enumerated paths.
+        let count = count_shortest_paths(&grid, 1, 9);
+        assert_eq!(count as usize, find_all_shortest_paths(&grid, 1, 9).len());
+
+        // On a 3x3 there are C(4,2) = 6 monotone shortest paths from corner to corner.
+        assert_eq!(count, 6);
+
+        // Missing number reports zero.
+        assert_eq!(count_shortest_paths(&grid, 1, 42), 0);
+    }
+
+    #[test]
+    fn test_configurable_moves() {
+        let grid = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9]
+        ];
+
+        // The default 4-connected set matches find_all_shortest_paths.
+        let default_moves = find_all_shortest_paths_moves(&grid, 1, 9, &Moves::four_connected());
+        assert_eq!(default_moves, find_all_shortest_paths(&grid, 1, 9));
+
+        // With diagonals the corner-to-corner distance drops to 2 and the only
+        // shortest route is two down-right steps.
+        let king = find_all_shortest_paths_moves(&grid, 1, 9, &Moves::eight_connected());
+        assert_eq!(king, vec![String::from("↘↘")]);
+
+        // A custom glyph set is honored.
+        let custom = Moves::from_offsets(vec![(0, 1, 'R'), (1, 0, 'D'), (0, -1, 'L'), (-1, 0, 'U')]);
+        let paths = find_all_shortest_paths_moves(&grid, 1, 9, &custom);
+        for path in &paths {
+            assert!(path.chars().all(|c| matches!(c, 'R' | 'D' | 'L' | 'U')));
+        }
+    }
 }
\ No newline at end of file